@@ -1,10 +1,11 @@
 //! Simple, fallible, and fun: Greatest common divisors made easy.
 //!
-//! This is a demonstration of three methods of calculating the greatest common divisor.  The
+//! This is a demonstration of four methods of calculating the greatest common divisor.  The
 //! algorithms used are as follows:
 //! - Euclid's Method
 //! - Consecutive Integer Checking Method
 //! - Middle School Procedure
+//! - Stein's (Binary) Method
 //!
 //! # Example
 //! The following example illustrates how to calculate the greatest common divisors (GCDs) for
@@ -32,6 +33,7 @@ pub enum GcdAlgorithms {
     Euclid,
     Middle,
     Consecutive,
+    Stein,
 }
 
 /// Uses provided algorithm to calculate the gcd of two numbers.
@@ -62,6 +64,7 @@ pub fn gcd_with_algorithm(a: i64, b: i64, algo: GcdAlgorithms) -> Result<u64, &'
             GcdAlgorithms::Euclid => Ok(euclid_gcd(a as i64, b as i64)),
             GcdAlgorithms::Consecutive => Ok(middle_school_gcd(a, b)),
             GcdAlgorithms::Middle => Ok(middle_school_gcd(a, b)),
+            GcdAlgorithms::Stein => Ok(binary_gcd(a, b)),
         }
     }
 }
@@ -86,37 +89,151 @@ pub fn gcd(a: i64, b: i64) -> Result<u64, &'static str> {
     // Run the middle school algorithm
     let middle = gcd_with_algorithm(a, b, GcdAlgorithms::Middle);
 
+    // Run Stein's (binary) algorithm as a fourth, division-free witness
+    let stein = gcd_with_algorithm(a, b, GcdAlgorithms::Stein);
+
     // Compare the algorithm's results and return accordingly
-    if euclid != consecutive || euclid != middle {
+    if euclid != consecutive || euclid != middle || euclid != stein {
         Err("GCD does not match across all algorithms.")
     } else {
         euclid
     }
 }
 
-/// Uses Euclid's Method of finding the GCD of two numbers.
+/// Folds `gcd` across an arbitrary slice of integers, mirroring how many standard-library GCDs
+/// accept any number of arguments.
 ///
-/// Euclid's Method is an incredibly simple algorithm for determining the Greatest Common Divisor
-/// (GCD) of two numbers.  The method can be applied iteratively or recursively; however, the
-/// recursive method is the simplest.
+/// # Errors
+///
+/// Returns the same undefined error as `gcd` when every element of `nums` is 0, or when `nums`
+/// is empty.
+pub fn gcd_many(nums: &[i64]) -> Result<u64, &'static str> {
+    // The only truly undefined case is every element being 0 (an empty slice is vacuously the
+    // same condition) — check it up front instead of letting an intermediate gcd(0, 0) inside
+    // the fold below stand in for it, which would wrongly reject inputs like [0, 0, 5]
+    if nums.iter().all(|&n| n == 0) {
+        return Err("GCD is undefined for input 0 and 0.");
+    }
+
+    // Fold left over every element starting from 0, relying on the identity gcd(g, 0) == g so
+    // that 0 acts as a neutral starting value and any zero elements are simply skipped over.
+    // Propagate gcd's own Result instead of assuming it can't error here — it can also fail when
+    // the algorithms disagree, not just on the zero case.
+    nums.iter().try_fold(0u64, |acc, &n| match (acc, n) {
+        (0, n) => Ok(n.unsigned_abs()),
+        (acc, 0) => Ok(acc),
+        (acc, n) => gcd(acc as i64, n),
+    })
+}
+
+/// Computes the least common multiple of two integers, the canonical companion task to GCD.
+///
+/// Divides before multiplying (`|a / gcd(a, b) * b|`) to reduce the chance of overflow, and
+/// defines `lcm(x, 0) == 0` to match the usual number-theoretic convention.
+///
+/// # Errors
+///
+/// Returns `Err(...)` when `gcd` errors, or when the result overflows `u64`.
+pub fn lcm(a: i64, b: i64) -> Result<u64, &'static str> {
+    // By convention, the LCM of anything and 0 is 0
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+
+    let divisor = gcd(a, b)?;
+
+    // Divide before multiplying to reduce the chance of overflowing u64
+    let reduced = a.unsigned_abs() / divisor;
+
+    reduced
+        .checked_mul(b.unsigned_abs())
+        .ok_or("LCM overflowed u64.")
+}
+
+/// Folds `lcm` across an arbitrary slice of integers, mirroring `gcd_many`.
+///
+/// # Errors
+///
+/// Returns `Err(...)` when `nums` is empty, or when any pairwise `lcm` call errors.
+pub fn lcm_many(nums: &[i64]) -> Result<u64, &'static str> {
+    // There's no meaningful LCM of an empty input
+    let Some((&first, rest)) = nums.split_first() else {
+        return Err("LCM is undefined for an empty input.");
+    };
+
+    // Fold left starting from the absolute value of the first element, since (unlike gcd(g, 0))
+    // lcm(g, 0) == 0 would wrongly zero out a lone non-zero element
+    rest.iter().try_fold(first.unsigned_abs(), |acc, &n| lcm(acc as i64, n))
+}
+
+/// Runs the extended Euclidean algorithm and returns the GCD together with its Bézout
+/// coefficients.
+///
+/// The extended Euclidean algorithm tracks the `s1, s2, t1, t2` coefficient pairs alongside the
+/// remainders and surfaces them, where `euclid_gcd` keeps only the gcd. The result
+/// is a triple `(gcd, x, y)` satisfying `a*x + b*y == gcd` (as a signed equation over `i64`),
+/// which is the building block for modular inverses and solving linear Diophantine equations.
 ///
 /// # Invariants
 ///
-/// This algorithm assumes that neither argument is 0; therefore, using such inputs is not
-/// guaranteed to be correct.
-pub fn euclid_gcd(a: i64, b: i64) -> u64 {
-    let (mut s1, mut t1, mut r1) = (0, 1, b);
-    let (mut s2, mut t2, mut r2) = (1, 0, a);
+/// If both `a` and `b` are 0, the GCD is 0 and the coefficients are also 0; every pair satisfies
+/// the Bézout identity trivially in that case, so (0, 0) is returned.
+pub fn extended_gcd(a: i64, b: i64) -> (u64, i64, i64) {
+    // gcd_with_algorithm returns an error for the undefined case; the signature here always
+    // produces a value, so report a gcd of 0 with zero coefficients instead
+    if a == 0 && b == 0 {
+        return (0, 0, 0);
+    }
+
+    // Track the sign of each operand so the coefficients can be flipped back at the end to
+    // match the original (possibly negative) inputs, consistent with gcd_with_algorithm's
+    // absolute-value bookkeeping
+    let sign_a = if a < 0 { -1 } else { 1 };
+    let sign_b = if b < 0 { -1 } else { 1 };
+
+    let ua = a.unsigned_abs();
+    let ub = b.unsigned_abs();
+
+    // Quick case out the zero-input cases just like gcd_with_algorithm does
+    if ua == 0 {
+        return (ub, 0, sign_b);
+    } else if ub == 0 {
+        return (ua, sign_a, 0);
+    }
+
+    // Euclid's coefficient-tracking loop, run on the absolute values so the
+    // resulting gcd is guaranteed non-negative. r1/r2 stay unsigned since they're just magnitudes
+    // (and unsigned_abs(i64::MIN) doesn't fit back in i64), while the coefficients are widened to
+    // i128 for the duration of the loop since a quotient this large can briefly outgrow i64 before
+    // being multiplied against a coefficient small enough to bring the product back in range.
+    let (mut s1, mut t1, mut r1): (i128, i128, u64) = (0, 1, ub);
+    let (mut s2, mut t2, mut r2): (i128, i128, u64) = (1, 0, ua);
 
     while r1 != 0 {
         let quotient = r2 / r1;
 
         (r2, r1) = (r1, r2 - quotient * r1);
-        (s2, s1) = (s1, s2 - quotient * s1);
-        (t2, t1) = (t1, t2 - quotient * t1);
+        (s2, s1) = (s1, s2 - quotient as i128 * s1);
+        (t2, t1) = (t1, t2 - quotient as i128 * t1);
     }
 
-    r2 as u64
+    // Un-abs the coefficients so the identity holds for the original signed a and b. The Bézout
+    // coefficients are bounded by b/gcd and a/gcd respectively, so they're guaranteed to fit back
+    // in i64 for any valid i64 input.
+    (r2, s2 as i64 * sign_a, t2 as i64 * sign_b)
+}
+
+/// Uses Euclid's Method of finding the GCD of two numbers.
+///
+/// Euclid's Method is an incredibly simple algorithm for determining the Greatest Common Divisor
+/// (GCD) of two numbers.  The method can be applied iteratively or recursively; however, the
+/// recursive method is the simplest.
+///
+/// This is a thin wrapper around `extended_gcd`, which runs the same coefficient-tracking loop
+/// but also surfaces the Bézout coefficients this function discards. It correctly handles 0 in
+/// either argument, since `extended_gcd` special-cases both.
+pub fn euclid_gcd(a: i64, b: i64) -> u64 {
+    extended_gcd(a, b).0
 }
 
 /// Uses the Consecutive Integer Method of finding the GCD of two numbers.
@@ -149,6 +266,47 @@ pub fn consecutive_gcd(a: u64, b: u64) -> u64 {
     }
 }
 
+/// Uses Stein's (binary) Method of finding the GCD of two numbers.
+///
+/// Stein's algorithm avoids division entirely, relying only on comparisons, subtraction, and
+/// shifts. It first pulls out the common factors of two shared by both operands, then repeatedly
+/// strips remaining factors of two from the larger operand and subtracts, shrinking the pair
+/// until one side hits zero.
+///
+/// # Panics
+///
+/// If either input is 0, its `trailing_zeros()` is 64, and shifting by 64 panics.
+pub fn binary_gcd(a: u64, b: u64) -> u64 {
+    let mut m = a;
+    let mut n = b;
+
+    // Pull out the shared factors of two up front; they get multiplied back in at the end
+    let shift = (m | n).trailing_zeros();
+
+    // n is kept odd for the rest of the function; only m gets re-shifted each iteration below
+    n >>= n.trailing_zeros();
+
+    loop {
+        // Strip m's factors of two so it's odd; only one side needs to be odd at a time for the
+        // subtraction step below to make progress
+        m >>= m.trailing_zeros();
+
+        // Ensure n <= m so that m -= n always leaves a non-negative result
+        if m < n {
+            std::mem::swap(&mut m, &mut n);
+        }
+
+        m -= n;
+
+        if m == 0 {
+            break;
+        }
+    }
+
+    // Restore the common factors of two that were set aside at the start
+    n << shift
+}
+
 /// Uses the algorithm known as the Sieve of Eratosthenes to determine primes up to n.
 ///
 /// The Sieve of Eratosthenes is an iterative algorithm that
@@ -229,19 +387,197 @@ pub fn occurrences(n: u64, prime: u64) -> u64 {
     occurrences
 }
 
+/// A small table of primes used to strip cheap factors before resorting to Pollard's rho.
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Computes `(a * b) % m` without overflowing, by widening the multiplication to `u128`.
+fn mulmod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// Computes `(base ^ exp) % m` using binary exponentiation built on `mulmod`.
+fn modpow(base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1u64;
+    let mut base = base % m;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mulmod(result, base, m);
+        }
+
+        exp >>= 1;
+        base = mulmod(base, base, m);
+    }
+
+    result
+}
+
+/// Deterministic Miller–Rabin primality test for the full `u64` range.
+///
+/// The witness set `{2,3,5,7,11,13,17,19,23,29,31,37}` is provably sufficient to make this
+/// deterministic (not merely probabilistic) for every 64-bit input.
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    // Knock out anything divisible by one of the small primes themselves or a multiple of one
+    for &p in SMALL_PRIMES.iter() {
+        if n == p {
+            return true;
+        }
+
+        if n.is_multiple_of(p) {
+            return false;
+        }
+    }
+
+    // Write n - 1 = d * 2^r with d odd
+    let mut d = n - 1;
+    let mut r = 0u32;
+
+    while d.is_multiple_of(2) {
+        d /= 2;
+        r += 1;
+    }
+
+    'witness: for &a in SMALL_PRIMES.iter() {
+        let mut x = modpow(a, d, n);
+
+        if x == 1 || x == n - 1 {
+            continue 'witness;
+        }
+
+        for _ in 0..r - 1 {
+            x = mulmod(x, x, n);
+
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+
+        // None of the squarings hit n - 1, so a is a witness to n's compositeness
+        return false;
+    }
+
+    true
+}
+
+/// Plain Euclidean GCD over u64, used internally by Pollard's rho for its cycle-detection step.
+fn euclid_gcd_u64(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Pollard's rho algorithm: finds a single non-trivial factor of a composite `n`.
+///
+/// Uses Floyd cycle detection over the iteration `x = (x*x + c) mod n`, retrying with a fresh
+/// constant `c` whenever a round collapses to the trivial factor `n`.
+fn pollard_rho(n: u64) -> u64 {
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+
+    let mut c: u64 = 1;
+
+    loop {
+        let f = |x: u64| (mulmod(x, x, n) + c) % n;
+
+        let (mut x, mut y, mut d) = (2u64, 2u64, 1u64);
+
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+
+            let diff = x.abs_diff(y);
+
+            if diff == 0 {
+                // The cycle collapsed without finding a factor; retry with a new c below
+                d = n;
+            } else {
+                d = euclid_gcd_u64(diff, n);
+            }
+        }
+
+        if d != n {
+            return d;
+        }
+
+        c += 1;
+    }
+}
+
+/// Recursively splits a composite `n` into primes (with repeats) via Pollard's rho, verifying
+/// each candidate with Miller-Rabin before accepting it as a leaf.
+fn factor_composite(n: u64, out: &mut Vec<u64>) {
+    if n == 1 {
+        return;
+    }
+
+    if is_prime(n) {
+        out.push(n);
+        return;
+    }
+
+    let factor = pollard_rho(n);
+
+    factor_composite(factor, out);
+    factor_composite(n / factor, out);
+}
+
+/// Factors `n` into its prime multiset across the full `u64` range.
+///
+/// Small factors are stripped first via trial division against a tiny prime table; whatever
+/// composite remainder is left is split using Pollard's rho with a deterministic Miller-Rabin
+/// primality check, recursing until every piece is prime. The result is the sorted `(prime,
+/// exponent)` tuples for `n`.
+pub fn factorize(n: u64) -> Vec<(u64, u64)> {
+    if n < 2 {
+        return vec![];
+    }
+
+    let mut remaining = n;
+    let mut raw_factors = vec![];
+
+    // Strip small factors by trial division first, since they're by far the most common and
+    // Pollard's rho is comparatively expensive to run on them
+    for &p in SMALL_PRIMES.iter() {
+        while remaining.is_multiple_of(p) {
+            raw_factors.push(p);
+            remaining /= p;
+        }
+    }
+
+    if remaining > 1 {
+        factor_composite(remaining, &mut raw_factors);
+    }
+
+    raw_factors.sort_unstable();
+
+    // Aggregate the sorted prime multiset into (prime, exponent) tuples
+    let mut factors: Vec<(u64, u64)> = vec![];
+
+    for p in raw_factors {
+        match factors.last_mut() {
+            Some((last_p, count)) if *last_p == p => *count += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+
+    factors
+}
+
 /// Gives the prime factorization of any given number n.
 ///
-/// The algorithm filters the output from prime_sieve for n and then uses occurrences to bundle
-/// the number of occurrences of any given factor into a tuple.
+/// Delegates to `factorize`, which scales to the full `u64` range via Pollard's rho and
+/// Miller-Rabin instead of the O(n) sieve this function used to rely on directly.
 pub fn prime_factors(n: u64) -> Vec<(u64, u64)> {
-    // Sieve the number to get all of the primes up to n
-    prime_sieve(n)
-        // Start an iterator chain
-        .iter()
-        // Filter for factors and then combine them with their occurrences
-        .filter_map(|&x| if n % x == 0 { Some((x, occurrences(n, x))) } else { None })
-        // Collect it into a vec and return
-        .collect()
+    factorize(n)
 }
 
 /// Uses Middle School Procedure to find the GCD of two numbers.
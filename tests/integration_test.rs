@@ -67,4 +67,311 @@ fn gcd_random() {
         // Finally compare the two algorithms to ensure they match
         assert_eq!(Ok(num_integer::gcd(a, b) as u64), gcd(a, b));
     }
+}
+
+// Tests that extended_gcd's gcd component matches gcd, and that the returned Bézout
+// coefficients actually satisfy a*x + b*y == gcd for all permutations from -100 to 100
+#[test]
+fn extended_gcd_bezout_identity() {
+    for a in -100..=100 {
+        for b in -100..=100 {
+            let (g, x, y) = extended_gcd(a, b);
+
+            // Check for correctness in the undefined case
+            if a == 0 && b == 0 {
+                assert_eq!((0, 0, 0), (g, x, y));
+                continue;
+            }
+
+            // Check that the gcd component matches the trusted 3rd party library
+            assert_eq!(num_integer::gcd(a, b) as u64, g);
+
+            // Check that the Bézout identity actually holds
+            assert_eq!(a * x + b * y, g as i64);
+        }
+    }
+}
+
+// Tests the Bézout identity against 100 random sets of numbers ranging from -100000 to 100000
+#[test]
+fn extended_gcd_bezout_identity_random() {
+    // Trusted 3rd party dependency used to generate random numbers
+    use rand::Rng;
+
+    // Create the random number generator
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        // Generate the first random number
+        let a: i64 = rng.gen_range(-100000..100000);
+        // Then the second random number
+        let b: i64 = rng.gen_range(-100000..100000);
+
+        if a == 0 && b == 0 {
+            continue;
+        }
+
+        let (g, x, y) = extended_gcd(a, b);
+
+        assert_eq!(num_integer::gcd(a, b) as u64, g);
+        assert_eq!(a * x + b * y, g as i64);
+    }
+}
+
+// Tests binary_gcd against all permutations of numbers ranging from 1 to 100 using the trusted
+// 3rd party library (binary_gcd assumes neither argument is 0, so 0 is excluded from the range)
+#[test]
+fn binary_gcd_one_to_hundred() {
+    for a in 1..=100 {
+        for b in 1..=100 {
+            assert_eq!(num_integer::gcd(a, b), binary_gcd(a, b));
+        }
+    }
+}
+
+// Tests binary_gcd against 100 random sets of numbers ranging from 1 to 100000
+#[test]
+fn binary_gcd_random() {
+    // Trusted 3rd party dependency used to generate random numbers
+    use rand::Rng;
+
+    // Create the random number generator
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        // Generate the first random number
+        let a: u64 = rng.gen_range(1..100000);
+        // Then the second random number
+        let b: u64 = rng.gen_range(1..100000);
+
+        // Finally compare the two algorithms to ensure they match
+        assert_eq!(num_integer::gcd(a, b), binary_gcd(a, b));
+    }
+}
+
+// A simple, independently-written trial division primality check used to verify factorize's
+// output against something other than factorize itself
+fn is_prime_trial_division(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+
+    let mut divisor = 2;
+
+    while divisor * divisor <= n {
+        if n % divisor == 0 {
+            return false;
+        }
+
+        divisor += 1;
+    }
+
+    true
+}
+
+// Checks that factorize's (prime, exponent) tuples are sorted, genuinely prime, and multiply
+// back out to n
+fn assert_factorization_is_valid(n: u64, factors: &[(u64, u64)]) {
+    let mut product: u64 = 1;
+    let mut last_prime = 0;
+
+    for &(prime, exponent) in factors {
+        assert!(prime > last_prime, "factors for {n} are not sorted: {factors:?}");
+        assert!(exponent > 0, "factor {prime} for {n} has a zero exponent");
+
+        product *= prime.pow(exponent as u32);
+        last_prime = prime;
+    }
+
+    assert_eq!(n, product, "factors {factors:?} do not multiply back out to {n}");
+}
+
+// Tests factorize against all numbers from 2 to 10,000, checking that the returned factors are
+// sorted, prime, and multiply back out to the original number
+#[test]
+fn factorize_small_range() {
+    for n in 2..=10_000u64 {
+        let factors = factorize(n);
+
+        assert_factorization_is_valid(n, &factors);
+
+        for &(prime, _) in &factors {
+            assert!(is_prime_trial_division(prime), "{prime} is not actually prime (factoring {n})");
+        }
+    }
+}
+
+// Tests factorize against inputs too large for the old O(n) sieve: a prime near 2^63, a large
+// semiprime (the product of two large primes), and a prime power
+#[test]
+fn factorize_large_inputs() {
+    // A prime close to 2^63
+    let large_prime = 9_223_372_036_854_775_783u64;
+    assert_eq!(vec![(large_prime, 1)], factorize(large_prime));
+
+    // The product of two large primes
+    let p = 998_244_353u64;
+    let q = 1_000_000_007u64;
+    assert_eq!(vec![(p, 1), (q, 1)], factorize(p * q));
+
+    // A prime power
+    let power = 3u64.pow(40);
+    assert_eq!(vec![(3, 40)], factorize(power));
+}
+
+// Tests factorize against 50 random numbers ranging from 1 to u64::MAX
+#[test]
+fn factorize_random() {
+    // Trusted 3rd party dependency used to generate random numbers
+    use rand::Rng;
+
+    // Create the random number generator
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..50 {
+        let n: u64 = rng.gen_range(1..=u64::MAX);
+
+        assert_factorization_is_valid(n, &factorize(n));
+    }
+}
+
+// Tests gcd_many's undefined cases: an empty slice and an all-zero slice
+#[test]
+fn gcd_many_undefined() {
+    assert_eq!(Err("GCD is undefined for input 0 and 0."), gcd_many(&[]));
+    assert_eq!(Err("GCD is undefined for input 0 and 0."), gcd_many(&[0, 0, 0]));
+}
+
+// Tests gcd_many against leading and interspersed zeros, which should simply be skipped over
+// rather than tripping the undefined error (the bug this guards against: gcd_many(&[0, 0, 5])
+// used to wrongly error instead of returning 5)
+#[test]
+fn gcd_many_skips_zeros() {
+    assert_eq!(Ok(5), gcd_many(&[0, 0, 5]));
+    assert_eq!(Ok(5), gcd_many(&[5, 0, 0]));
+    assert_eq!(Ok(5), gcd_many(&[0, 5, 0]));
+}
+
+// Tests gcd_many against a single element, which should return its absolute value
+#[test]
+fn gcd_many_single_element() {
+    assert_eq!(Ok(7), gcd_many(&[7]));
+    assert_eq!(Ok(7), gcd_many(&[-7]));
+}
+
+// Tests gcd_many against all 3-element permutations of numbers ranging from 0 to 30, comparing
+// against a manual fold of the trusted 3rd party library
+#[test]
+fn gcd_many_small_range() {
+    for a in 0..=30i64 {
+        for b in 0..=30i64 {
+            for c in 0..=30i64 {
+                let nums = [a, b, c];
+
+                if a == 0 && b == 0 && c == 0 {
+                    assert_eq!(Err("GCD is undefined for input 0 and 0."), gcd_many(&nums));
+                    continue;
+                }
+
+                let expected = num_integer::gcd(num_integer::gcd(a, b), c) as u64;
+                assert_eq!(Ok(expected), gcd_many(&nums));
+            }
+        }
+    }
+}
+
+// Tests gcd_many against 100 random slices of 5 numbers ranging from -100000 to 100000
+#[test]
+fn gcd_many_random() {
+    // Trusted 3rd party dependency used to generate random numbers
+    use rand::Rng;
+
+    // Create the random number generator
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let nums: Vec<i64> = (0..5).map(|_| rng.gen_range(-100000..100000)).collect();
+
+        if nums.iter().all(|&n| n == 0) {
+            continue;
+        }
+
+        let expected = nums.iter().fold(0i64, |acc, &n| num_integer::gcd(acc, n)) as u64;
+
+        assert_eq!(Ok(expected), gcd_many(&nums));
+    }
+}
+
+// Test the defined zero cases for lcm: lcm(x, 0) == 0 by convention
+#[test]
+fn lcm_zero() {
+    assert_eq!(Ok(0), lcm(0, 10));
+    assert_eq!(Ok(0), lcm(10, 0));
+    assert_eq!(Ok(0), lcm(0, 0));
+}
+
+// Tests lcm against all permutations of numbers ranging from 0 to 100 using the trusted 3rd
+// party library
+#[test]
+fn lcm_one_to_hundred() {
+    for a in 0..=100i64 {
+        for b in 0..=100i64 {
+            assert_eq!(Ok(num_integer::lcm(a, b) as u64), lcm(a, b));
+        }
+    }
+}
+
+// Tests lcm against 100 random sets of numbers ranging from -1000 to 1000, small enough to keep
+// the product well clear of u64 overflow
+#[test]
+fn lcm_random() {
+    // Trusted 3rd party dependency used to generate random numbers
+    use rand::Rng;
+
+    // Create the random number generator
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..100 {
+        let a: i64 = rng.gen_range(-1000..1000);
+        let b: i64 = rng.gen_range(-1000..1000);
+
+        assert_eq!(Ok(num_integer::lcm(a, b) as u64), lcm(a, b));
+    }
+}
+
+// Tests that lcm reports an overflow error instead of silently wrapping
+//
+// i64::MAX is odd, so gcd(i64::MAX, 4) is 1 and nothing is divided out before multiplying:
+// i64::MAX * 4 still comfortably overflows u64.
+#[test]
+fn lcm_overflow() {
+    assert_eq!(Err("LCM overflowed u64."), lcm(i64::MAX, 4));
+}
+
+// Tests lcm_many's undefined case: an empty slice
+#[test]
+fn lcm_many_undefined() {
+    assert_eq!(Err("LCM is undefined for an empty input."), lcm_many(&[]));
+}
+
+// Tests lcm_many against a single element, which should return its absolute value
+#[test]
+fn lcm_many_single_element() {
+    assert_eq!(Ok(7), lcm_many(&[7]));
+    assert_eq!(Ok(7), lcm_many(&[-7]));
+}
+
+// Tests lcm_many against all 3-element permutations of numbers ranging from 0 to 10, comparing
+// against a manual fold of the trusted 3rd party library
+#[test]
+fn lcm_many_small_range() {
+    for a in 0..=10i64 {
+        for b in 0..=10i64 {
+            for c in 0..=10i64 {
+                let expected = num_integer::lcm(num_integer::lcm(a, b), c) as u64;
+
+                assert_eq!(Ok(expected), lcm_many(&[a, b, c]));
+            }
+        }
+    }
 }
\ No newline at end of file